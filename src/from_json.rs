@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{JsonValue, Lexer, NextValueError};
+
+/// Failure to map a parsed [`JsonValue`] onto a Rust type, either because the
+/// source text itself didn't parse or because its shape didn't match `T`.
+#[derive(Debug)]
+pub enum FromJsonError {
+    TypeMismatch,
+    Parse(String),
+}
+
+impl fmt::Display for FromJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromJsonError::TypeMismatch => write!(f, "value did not match the expected type"),
+            FromJsonError::Parse(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<NextValueError> for FromJsonError {
+    fn from(err: NextValueError) -> Self {
+        FromJsonError::Parse(format!("{:?}", err))
+    }
+}
+
+/// Maps a [`JsonValue`] onto a Rust type, the way serde_json's deserializer
+/// maps JSON onto a caller's structures, without requiring a derive macro.
+pub trait FromJson: Sized {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError>;
+}
+
+/// Parses `input` and converts it straight into `T`.
+pub fn from_str<T: FromJson>(input: &str) -> Result<T, FromJsonError> {
+    let mut lexer = Lexer::new(input);
+    let value = lexer.next_value()?;
+    T::from_json(&value)
+}
+
+impl FromJson for String {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        value.as_str().map(str::to_string).ok_or(FromJsonError::TypeMismatch)
+    }
+}
+
+impl FromJson for bool {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        value.as_bool().ok_or(FromJsonError::TypeMismatch)
+    }
+}
+
+macro_rules! impl_from_json_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl FromJson for $t {
+                fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+                    value.as_f64().map(|n| n as $t).ok_or(FromJsonError::TypeMismatch)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_float!(f32, f64);
+
+// Integers go through `Number::as_i64`/`as_u64` first, so an exact integer
+// lexeme (e.g. `10000000000000001`) round-trips precisely instead of being
+// routed through a lossy `f64` the way the float impls above are. Numbers
+// that aren't an exact integer fitting the target (fractional, out of the
+// target's range, or of the wrong sign) fall back to the `f64` cast, which
+// truncates and saturates.
+macro_rules! impl_from_json_signed {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl FromJson for $t {
+                fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+                    if let Some(n) = value.as_i64().and_then(|n| <$t>::try_from(n).ok()) {
+                        return Ok(n);
+                    }
+                    value.as_f64().map(|n| n as $t).ok_or(FromJsonError::TypeMismatch)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_signed!(i8, i16, i32, i64, isize);
+
+macro_rules! impl_from_json_unsigned {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl FromJson for $t {
+                fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+                    if let Some(n) = value.as_u64().and_then(|n| <$t>::try_from(n).ok()) {
+                        return Ok(n);
+                    }
+                    value.as_f64().map(|n| n as $t).ok_or(FromJsonError::TypeMismatch)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_unsigned!(u8, u16, u32, u64, usize);
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        value
+            .as_array()
+            .ok_or(FromJsonError::TypeMismatch)?
+            .iter()
+            .map(T::from_json)
+            .collect()
+    }
+}
+
+impl<T: FromJson> FromJson for HashMap<String, T> {
+    fn from_json(value: &JsonValue) -> Result<Self, FromJsonError> {
+        value
+            .as_object()
+            .ok_or(FromJsonError::TypeMismatch)?
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), T::from_json(v)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_str;
+
+    #[test]
+    fn large_integers_round_trip_exactly() {
+        assert_eq!(from_str::<i64>("10000000000000001").unwrap(), 10000000000000001i64);
+        assert_eq!(from_str::<u64>("10000000000000001").unwrap(), 10000000000000001u64);
+    }
+
+    #[test]
+    fn fractional_numbers_still_cast_to_integers() {
+        assert_eq!(from_str::<i32>("3.0").unwrap(), 3);
+    }
+
+    #[test]
+    fn collections_and_scalars() {
+        assert_eq!(from_str::<Vec<i32>>("[1,2,3]").unwrap(), vec![1, 2, 3]);
+        assert_eq!(from_str::<String>("\"hello\"").unwrap(), "hello");
+        assert!(from_str::<bool>("true").unwrap());
+    }
+}
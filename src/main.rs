@@ -1,104 +1,149 @@
-use std::{collections::HashMap, iter::Peekable, str::Chars};
+use std::collections::HashMap;
+use std::fmt;
+
+mod from_json;
+mod number;
+mod serializer;
+mod token;
+mod tokenizer;
+mod value;
+
+pub use from_json::{from_str, FromJson, FromJsonError};
+pub use number::Number;
+
+use token::{Token, TokenKind};
+use tokenizer::Tokenizer;
 
 #[derive(Debug)]
-#[allow(dead_code)]
-enum JsonValue {
+pub enum JsonValue {
     Boolean(bool),
     String(String),
-    Number(f64),
+    Number(Number),
     Array(Vec<JsonValue>),
     Object(HashMap<String, JsonValue>),
     Null,
 }
 
 struct Lexer<'a> {
-    pub input: Peekable<Chars<'a>>,
+    input: &'a str,
+    tokens: std::iter::Peekable<Tokenizer<'a>>,
 }
 
 #[derive(Debug)]
-#[allow(dead_code)]
 enum NextValueError {
     Eof,
-    ParseError(String),
+    ParseError(ParseError),
+}
+
+impl fmt::Display for NextValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NextValueError::Eof => write!(f, "unexpected end of input"),
+            NextValueError::ParseError(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// A parse failure with the location in the source where it was detected,
+/// so a caller can render e.g. `file.json:12:5: trailing comma`.
+#[derive(Debug)]
+pub(crate) struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub offset: usize,
+    pub msg: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{} (byte {}): {}", self.line, self.col, self.offset, self.msg)
+    }
 }
 
 impl<'a> Lexer<'a> {
-    fn next_whitespaces(&mut self) {
-        loop {
-            match self.input.peek() {
-                Some(c) if c.is_ascii_whitespace() => {
-                    self.input.next();
-                },
-                _ => return,
+    fn new(input: &'a str) -> Self {
+        Lexer {
+            input,
+            tokens: Tokenizer::new(input).peekable(),
+        }
+    }
+
+    /// Translates a byte offset into a 1-based (line, col) pair by scanning
+    /// the source up to it. Done lazily, only when an error is reported,
+    /// since the tokenizer itself never needs to track position.
+    fn position_at(&self, offset: usize) -> (usize, usize) {
+        let mut line = 1;
+        let mut col = 1;
+        for c in self.input[..offset].chars() {
+            if c == '\n' {
+                line += 1;
+                col = 1;
+            } else {
+                col += 1;
             }
         }
+        (line, col)
     }
 
-    pub fn next_value(&mut self) -> Result<JsonValue, NextValueError> {
-        self.next_whitespaces();
-
-        match self.input.peek().copied() {
-            Some(c) => match c {
-                c if c == '-' || c.is_numeric() => {
-                    match self.next_number() {
-                        Ok(n) => Ok(JsonValue::Number(n)),
-                        Err(e) => {
-                            Err(NextValueError::ParseError(format!("parse number error: {:#?}", e)))
-                        },
-                    }
-                },
-                '{' => {
-                    match self.next_object() {
-                        Ok(obj) => Ok(JsonValue::Object(obj)),
-                        Err(err) => Err(err),
-                    }
-                },
-                '[' => {
-                    match self.next_array() {
-                        Ok(arr) => Ok(JsonValue::Array(arr)),
-                        Err(err) => Err(err),
-                    }
-                },
-                '"' => {
-                    match self.next_string() {
-                        Ok(s) => Ok(JsonValue::String(s)),
-                        Err(err) => Err(err),
-                    }
-                },
-                'n' => {
-                    if self.expect_next("null") && self.validate_residuals() {
-                        return Ok(JsonValue::Null);
-                    }
-                    Err(NextValueError::ParseError("Got invalid identifier 1".to_string()))
-                },
-                'f' => {
-                    if self.expect_next("false") && self.validate_residuals() {
-                        return Ok(JsonValue::Boolean(false));
-                    }
-                    Err(NextValueError::ParseError("Got invalid identifier 2".to_string()))
-                },
-                't' => {
-                    if self.expect_next("true") && self.validate_residuals() {
-                        return Ok(JsonValue::Boolean(true));
-                    }
-                    Err(NextValueError::ParseError("Got invalid identifier 3".to_string()))
-                },
-                _ => {
-                    Err(NextValueError::ParseError(format!("unexpected character: {}", c)))
-                },
+    fn err_at(&self, offset: usize, msg: impl Into<String>) -> NextValueError {
+        let (line, col) = self.position_at(offset);
+        NextValueError::ParseError(ParseError { line, col, offset, msg: msg.into() })
+    }
+
+    /// Errors if the token following the one just consumed isn't a valid
+    /// value boundary (a comma, a closing bracket/brace, or end of input).
+    /// Catches e.g. `truefoo` or `123abc`, where the tokenizer happily
+    /// splits off a second token right after the first.
+    fn expect_boundary(&mut self) -> Result<(), NextValueError> {
+        let next = self.tokens.peek().copied();
+        match next {
+            None => Ok(()),
+            Some(tok) => match tok.kind {
+                TokenKind::Comma | TokenKind::RBrace | TokenKind::RBracket => Ok(()),
+                _ => Err(self.err_at(tok.span.start, "unexpected trailing characters after value")),
             },
+        }
+    }
+
+    pub fn next_value(&mut self) -> Result<JsonValue, NextValueError> {
+        match self.tokens.next() {
+            Some(tok) => self.value_from_token(tok),
             None => Err(NextValueError::Eof),
         }
     }
 
-    fn validate_residuals(&mut self) -> bool {
-        self.next_whitespaces();
-        let residual = self.input.peek().copied();
-        matches!(residual, None | Some(',') | Some('}') | Some(']'))
+    fn value_from_token(&mut self, tok: Token) -> Result<JsonValue, NextValueError> {
+        match tok.kind {
+            TokenKind::LBrace => self.next_object().map(JsonValue::Object),
+            TokenKind::LBracket => self.next_array().map(JsonValue::Array),
+            TokenKind::String => self.decode_string(tok).map(JsonValue::String),
+            TokenKind::Number => {
+                let n = self.parse_number(tok)?;
+                self.expect_boundary()?;
+                Ok(JsonValue::Number(n))
+            },
+            TokenKind::True if !tok.malformed => {
+                self.expect_boundary()?;
+                Ok(JsonValue::Boolean(true))
+            },
+            TokenKind::False if !tok.malformed => {
+                self.expect_boundary()?;
+                Ok(JsonValue::Boolean(false))
+            },
+            TokenKind::Null if !tok.malformed => {
+                self.expect_boundary()?;
+                Ok(JsonValue::Null)
+            },
+            TokenKind::True | TokenKind::False | TokenKind::Null => {
+                Err(self.err_at(tok.span.start, "invalid identifier"))
+            },
+            TokenKind::RBrace | TokenKind::RBracket | TokenKind::Colon | TokenKind::Comma | TokenKind::Unknown => {
+                Err(self.err_at(tok.span.start, format!("unexpected character: {}", tok.span.as_str(self.input))))
+            },
+        }
     }
 
     fn next_array(&mut self) -> Result<Vec<JsonValue>, NextValueError> {
-        self.input.next(); // consumes [
         let mut accepts_closing = true;
         let mut accepts_comma = false;
         let mut accepts_value = true;
@@ -107,23 +152,22 @@ impl<'a> Lexer<'a> {
         let mut values = vec![];
 
         loop {
-            self.next_whitespaces();
-            match self.input.peek().copied() {
-                Some(']') => {
+            match self.tokens.peek().copied() {
+                Some(tok) if tok.kind == TokenKind::RBracket => {
                     if last_was_comma {
-                        return Err(NextValueError::ParseError("trailing comma".to_string()));
+                        return Err(self.err_at(tok.span.start, "trailing comma"));
                     }
                     if !accepts_closing {
-                        return Err(NextValueError::ParseError("unexpected ']'".to_string()));
+                        return Err(self.err_at(tok.span.start, "unexpected ']'"));
                     }
-                    self.input.next();
+                    self.tokens.next();
                     return Ok(values);
-                }
-                Some(',') => {
+                },
+                Some(tok) if tok.kind == TokenKind::Comma => {
                     if !accepts_comma {
-                        return Err(NextValueError::ParseError("unexpected comma".to_string()));
+                        return Err(self.err_at(tok.span.start, "unexpected comma"));
                     }
-                    self.input.next();
+                    self.tokens.next();
                     accepts_closing = false;
                     accepts_comma = false;
                     accepts_value = true;
@@ -131,11 +175,12 @@ impl<'a> Lexer<'a> {
                     continue;
                 },
                 None => {
-                    return Err(NextValueError::ParseError("unexpected EOF while parsing array".to_string()));
+                    return Err(self.err_at(self.input.len(), "unexpected EOF while parsing array"));
                 },
                 _ => {
                     if !accepts_value {
-                        return Err(NextValueError::ParseError("unexpected value".to_string()));
+                        let start = self.tokens.peek().map(|t| t.span.start).unwrap_or(self.input.len());
+                        return Err(self.err_at(start, "unexpected value"));
                     }
                     match self.next_value() {
                         Ok(value) => {
@@ -154,7 +199,6 @@ impl<'a> Lexer<'a> {
     }
 
     fn next_object(&mut self) -> Result<HashMap<String, JsonValue>, NextValueError> {
-        self.input.next(); // consumes [
         let mut accepts_closing = true;
         let mut accepts_comma = false;
         let mut accepts_key = true;
@@ -166,23 +210,22 @@ impl<'a> Lexer<'a> {
         let mut map = HashMap::new();
 
         loop {
-            self.next_whitespaces();
-            match self.input.peek().copied() {
-                Some('}') => {
+            match self.tokens.peek().copied() {
+                Some(tok) if tok.kind == TokenKind::RBrace => {
                     if last_was_comma {
-                        return Err(NextValueError::ParseError("trailing comma".to_string()));
+                        return Err(self.err_at(tok.span.start, "trailing comma"));
                     }
                     if !accepts_closing {
-                        return Err(NextValueError::ParseError("unexpected '}'".to_string()));
+                        return Err(self.err_at(tok.span.start, "unexpected '}'"));
                     }
-                    self.input.next();
+                    self.tokens.next();
                     return Ok(map);
                 },
-                Some(',') => {
+                Some(tok) if tok.kind == TokenKind::Comma => {
                     if !accepts_comma {
-                        return Err(NextValueError::ParseError("unexpected comma".to_string()));
+                        return Err(self.err_at(tok.span.start, "unexpected comma"));
                     }
-                    self.input.next();
+                    self.tokens.next();
                     accepts_closing = false;
                     accepts_comma = false;
                     accepts_value = false;
@@ -191,18 +234,19 @@ impl<'a> Lexer<'a> {
                     last_was_colon = false;
                     continue;
                 },
-                Some(':') => {
+                Some(tok) if tok.kind == TokenKind::Colon => {
                     if last_was_comma || !accepts_value || last_was_colon {
-                        return Err(NextValueError::ParseError("unexpected colon".to_string()));
+                        return Err(self.err_at(tok.span.start, "unexpected colon"));
                     }
-                    self.input.next();
+                    self.tokens.next();
                     last_was_colon = true;
                     continue;
                 },
                 None => {
-                    return Err(NextValueError::ParseError("unexpected EOF while parsing array".to_string()));
+                    return Err(self.err_at(self.input.len(), "unexpected EOF while parsing object"));
                 },
                 _ => {
+                    let start = self.tokens.peek().map(|t| t.span.start).unwrap_or(self.input.len());
                     match self.next_value() {
                         Ok(JsonValue::String(key)) => {
                             if accepts_key {
@@ -224,7 +268,7 @@ impl<'a> Lexer<'a> {
                         },
                         Ok(value) => {
                             if !accepts_value {
-                                return Err(NextValueError::ParseError("unexpected value".to_string()));
+                                return Err(self.err_at(start, "unexpected value"));
                             }
                             map.insert(last_key.clone(), value);
                             accepts_value = false;
@@ -242,155 +286,250 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn next_string(&mut self) -> Result<String, NextValueError> {
-        self.input.next();
-        let mut s = "".to_string();
+    fn decode_string(&self, tok: Token) -> Result<String, NextValueError> {
+        if tok.malformed {
+            return Err(self.err_at(tok.span.end, "unexpected EOF while parsing string"));
+        }
 
-        loop {
-            match self.input.next() {
-                Some('"') => {
-                    return Ok(s);
-                },
-                Some(c) => {
-                    s.push(c);
-                },
-                None => {
-                    return Err(NextValueError::ParseError("unexpected EOF while parsing string".to_string()));
+        let raw = tok.span.as_str(self.input);
+        let inner = &raw[1..raw.len() - 1];
+        let inner_start = tok.span.start + 1;
+
+        let mut out = String::with_capacity(inner.len());
+        let mut chars = inner.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            let at = inner_start + i;
+            match c {
+                '\\' => out.push(self.decode_escape(&mut chars, inner_start, at)?),
+                c if (c as u32) < 0x20 => {
+                    return Err(self.err_at(at, format!("control character in string: {:#04x}", c as u32)));
                 },
+                c => out.push(c),
             }
         }
-    }
-
-    fn expect_next(&mut self, expect: &str) -> bool {
-        let mut expect_iter = expect.chars().peekable();
 
-        loop {
-            let e = expect_iter.peek().copied();
-            let c = self.input.peek().copied();
+        Ok(out)
+    }
 
-            if e == c {
-                self.input.next();
-                expect_iter.next();
-                continue;
-            }
-            if e.is_none() {
-                return true;
-            }
-            return false;
+    fn decode_escape(
+        &self,
+        chars: &mut std::iter::Peekable<std::str::CharIndices>,
+        inner_start: usize,
+        backslash_at: usize,
+    ) -> Result<char, NextValueError> {
+        match chars.next() {
+            Some((_, '"')) => Ok('"'),
+            Some((_, '\\')) => Ok('\\'),
+            Some((_, '/')) => Ok('/'),
+            Some((_, 'b')) => Ok('\u{0008}'),
+            Some((_, 'f')) => Ok('\u{000C}'),
+            Some((_, 'n')) => Ok('\n'),
+            Some((_, 'r')) => Ok('\r'),
+            Some((_, 't')) => Ok('\t'),
+            Some((_, 'u')) => {
+                let high = self.decode_unicode_escape(chars, inner_start)?;
+                if (0xD800..=0xDBFF).contains(&high) {
+                    if !matches!(chars.next(), Some((_, '\\'))) || !matches!(chars.next(), Some((_, 'u'))) {
+                        return Err(self.err_at(backslash_at, "unpaired UTF-16 surrogate"));
+                    }
+                    let low = self.decode_unicode_escape(chars, inner_start)?;
+                    if !(0xDC00..=0xDFFF).contains(&low) {
+                        return Err(self.err_at(backslash_at, "invalid low surrogate"));
+                    }
+                    let codepoint = 0x10000u32 + (((high - 0xD800) as u32) << 10) + (low - 0xDC00) as u32;
+                    return char::from_u32(codepoint)
+                        .ok_or_else(|| self.err_at(backslash_at, "invalid surrogate pair"));
+                }
+                if (0xDC00..=0xDFFF).contains(&high) {
+                    return Err(self.err_at(backslash_at, "unpaired UTF-16 surrogate"));
+                }
+                char::from_u32(high as u32).ok_or_else(|| self.err_at(backslash_at, "invalid unicode escape"))
+            },
+            Some((_, other)) => Err(self.err_at(backslash_at, format!("invalid escape sequence: \\{}", other))),
+            None => Err(self.err_at(backslash_at, "unexpected EOF while parsing escape sequence")),
         }
     }
 
-    fn next_number(&mut self) -> Result<f64, NextValueError> {
-        let mut is_negative = false;
-        let mut is_scientific_notation = false;
-        let mut holds_value = false;
-        let mut is_decimal_part = false;
-        let mut is_positive_scientific_exponential = true;
+    fn decode_unicode_escape(
+        &self,
+        chars: &mut std::iter::Peekable<std::str::CharIndices>,
+        inner_start: usize,
+    ) -> Result<u16, NextValueError> {
+        let mut code: u16 = 0;
+        for _ in 0..4 {
+            let (i, c) = chars
+                .next()
+                .ok_or_else(|| self.err_at(inner_start, "unexpected EOF in \\u escape"))?;
+            let digit = c
+                .to_digit(16)
+                .ok_or_else(|| self.err_at(inner_start + i, format!("invalid hex digit in \\u escape: {}", c)))?;
+            code = code * 16 + digit as u16;
+        }
+        Ok(code)
+    }
 
-        let mut num: f64 = 0.0;
-        let mut decimal_mult: f64 = 1.0;
-        let mut scientific_exponent: u32 = 0;
+    fn parse_number(&self, tok: Token) -> Result<Number, NextValueError> {
+        let raw = tok.span.as_str(self.input);
+        if tok.malformed {
+            return Err(self.err_at(tok.span.start, format!("invalid number: {}", raw)));
+        }
 
-        loop {
-            let next_char = match self.input.peek().copied() {
-                Some(c) => c,
-                None => {
-                    return if holds_value {
-                        break;
-                    } else {
-                        Err(NextValueError::ParseError("expected number got EOF".to_string()))
-                    }
-                }
-            };
+        let is_integer = !raw.contains(['.', 'e', 'E']);
 
-            match next_char {
-                '.' => {
-                    self.input.next();
-                    if is_decimal_part {
-                        return Err(NextValueError::ParseError("unexpected punto".to_string()));
-                    }
-                    is_decimal_part = true;
-                    continue;
-                },
-                '-' => {
-                    self.input.next();
-                    if is_negative {
-                        return Err(NextValueError::ParseError("double negative".to_string()));
-                    }
-                    is_negative = true;
-                    continue;
-                },
-                'e' | 'E' => {
-                    self.input.next();
-                    match self.input.peek().copied() {
-                        Some('+') => {
-                            is_positive_scientific_exponential = true;
-                            is_scientific_notation = true;
-                            self.input.next();
-                        },
-                        Some(c) if c.is_numeric() => {
-                            is_positive_scientific_exponential = true;
-                            is_scientific_notation = true;
-                        },
-                        Some('-') => {
-                            is_positive_scientific_exponential = false;
-                            is_scientific_notation = true;
-                            self.input.next();
-                        },
-                        _ => {
-                            return Err(NextValueError::ParseError("bad science".to_string()));
-                        },
-                    }
-                },
-                c if c.is_numeric() => {
-                    self.input.next(); // HERE
-                    let d = c.to_digit(10).unwrap();
-                    if is_scientific_notation {
-                        scientific_exponent *= 10;
-                        scientific_exponent += d;
-                        continue;
-                    }
-                    num *= 10.0;
-                    num += d as f64;
-                    holds_value = true;
-                    if is_decimal_part {
-                        decimal_mult *= 0.1;
-                    }
-                },
-                c if c == ',' || c == ']' || c == '}' || c.is_whitespace() => {
-                    if holds_value {
-                        break;
-                    }
-                    return Err(NextValueError::ParseError("expected number got EOF".to_string()));
-                },
-                c => {
-                    return Err(NextValueError::ParseError(format!("unexpected character: {}", c)));
+        if is_integer {
+            if raw.starts_with('-') {
+                if let Ok(n) = raw.parse::<i64>() {
+                    return Ok(Number::NegInt(n));
                 }
+            } else if let Ok(n) = raw.parse::<u64>() {
+                return Ok(Number::PosInt(n));
             }
-        };
-
-        num = if is_negative { -num } else { num };
-        if !is_scientific_notation {
-            return Ok(num * decimal_mult);
         }
 
-        let exponent = f64::powi(10.0, scientific_exponent as i32);
-
-        if is_positive_scientific_exponential {
-            Ok((num * decimal_mult) * exponent)
-        } else {
-            Ok((num * decimal_mult) / exponent)
+        let n = raw
+            .parse::<f64>()
+            .map_err(|_| self.err_at(tok.span.start, format!("invalid number: {}", raw)))?;
+        // A lexeme like `1e400` is valid JSON grammar but overflows `f64` to
+        // infinity; letting that through would make the serializer emit the
+        // bare word `inf`, which isn't valid JSON.
+        if !n.is_finite() {
+            return Err(self.err_at(tok.span.start, format!("number out of range: {}", raw)));
         }
+        Ok(Number::Float(n))
     }
 }
 
 fn main() {
     let input = include_str!("./data.json");
 
-    let mut lexer = Lexer {
-        input: input.chars().peekable(),
-    };
+    let mut lexer = Lexer::new(input);
 
-    let parsed_value = lexer.next_value();
-    println!("{:#?}", parsed_value);
+    match lexer.next_value() {
+        Ok(value) => {
+            println!("{:#?}", value);
+            println!("{}", serializer::to_string(&value));
+            println!("{}", serializer::to_string_pretty(&value, 2));
+        },
+        Err(err) => eprintln!("{}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<JsonValue, NextValueError> {
+        Lexer::new(input).next_value()
+    }
+
+    fn parse_string(input: &str) -> String {
+        match parse(input).expect("expected a successful parse") {
+            JsonValue::String(s) => s,
+            other => panic!("expected a string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn basic_escapes() {
+        assert_eq!(parse_string(r#""a\nb\tc\"d\\e""#), "a\nb\tc\"d\\e");
+    }
+
+    #[test]
+    fn unicode_escape() {
+        assert_eq!(parse_string(r#""caf\u00e9""#), "café");
+    }
+
+    #[test]
+    fn surrogate_pair_escape() {
+        // U+1F600 GRINNING FACE, as a \u-escaped UTF-16 surrogate pair.
+        assert_eq!(parse_string(r#""\ud83d\ude00""#), "\u{1F600}");
+    }
+
+    #[test]
+    fn lone_surrogate_is_rejected() {
+        assert!(matches!(parse(r#""\ud83d""#), Err(NextValueError::ParseError(_))));
+    }
+
+    #[test]
+    fn literal_control_character_is_rejected() {
+        assert!(matches!(parse("\"a\nb\""), Err(NextValueError::ParseError(_))));
+    }
+
+    #[test]
+    fn rejects_trailing_characters_after_a_value() {
+        assert!(parse("truefoo").is_err());
+        assert!(parse("123abc").is_err());
+    }
+
+    #[test]
+    fn parses_large_and_negative_integers_exactly() {
+        match parse("10000000000000001").unwrap() {
+            JsonValue::Number(n) => assert_eq!(n.as_u64(), Some(10000000000000001)),
+            other => panic!("expected a number, got {:?}", other),
+        }
+        match parse("-42").unwrap() {
+            JsonValue::Number(n) => assert_eq!(n.as_i64(), Some(-42)),
+            other => panic!("expected a number, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_floats_and_scientific_notation() {
+        match parse("3.5").unwrap() {
+            JsonValue::Number(n) => assert_eq!(n.as_f64(), Some(3.5)),
+            other => panic!("expected a number, got {:?}", other),
+        }
+        match parse("-2.5e2").unwrap() {
+            JsonValue::Number(n) => assert_eq!(n.as_f64(), Some(-250.0)),
+            other => panic!("expected a number, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_error_reports_line_and_col_of_the_failure() {
+        match parse("{\n  \"a\": 1,\n  \"b\": @\n}") {
+            Err(NextValueError::ParseError(err)) => {
+                assert_eq!(err.line, 3);
+                assert_eq!(err.col, 8);
+                assert_eq!(err.offset, 19);
+            },
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_error_display_includes_location_and_message() {
+        match parse("@") {
+            Err(err @ NextValueError::ParseError(_)) => {
+                assert_eq!(err.to_string(), "1:1 (byte 0): unexpected character: @");
+            },
+            other => panic!("expected a ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn numbers_that_overflow_f64_are_rejected() {
+        assert!(matches!(parse("1e400"), Err(NextValueError::ParseError(_))));
+        assert!(matches!(parse("-1e400"), Err(NextValueError::ParseError(_))));
+    }
+
+    #[test]
+    fn large_but_finite_floats_still_round_trip_to_valid_json() {
+        let value = parse("1e300").unwrap();
+        let text = serializer::to_string(&value);
+        assert!(text.parse::<f64>().unwrap().is_finite());
+    }
+
+    #[test]
+    fn integer_lexemes_stay_integers_not_floats() {
+        match parse("42").unwrap() {
+            JsonValue::Number(n) => assert_eq!(n.as_i64(), Some(42)),
+            other => panic!("expected a number, got {:?}", other),
+        }
+        match parse("3.0").unwrap() {
+            JsonValue::Number(n) => assert_eq!(n.as_i64(), None),
+            other => panic!("expected a number, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,135 @@
+use crate::JsonValue;
+
+/// Serializes a [`JsonValue`] into compact JSON text, with no extra whitespace.
+pub(crate) fn to_string(value: &JsonValue) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out, None, 0);
+    out
+}
+
+/// Serializes a [`JsonValue`] into pretty-printed JSON text, indenting nested
+/// arrays/objects by `indent` spaces per level. Object keys are sorted so the
+/// output is deterministic across runs (`JsonValue::Object` is a `HashMap`,
+/// whose iteration order is otherwise unspecified).
+pub(crate) fn to_string_pretty(value: &JsonValue, indent: usize) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out, Some(indent), 0);
+    out
+}
+
+fn write_value(value: &JsonValue, out: &mut String, indent: Option<usize>, depth: usize) {
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Boolean(b) => out.push_str(if *b { "true" } else { "false" }),
+        JsonValue::Number(n) => out.push_str(&n.to_string()),
+        JsonValue::String(s) => write_string(s, out),
+        JsonValue::Array(items) => write_array(items, out, indent, depth),
+        JsonValue::Object(map) => write_object(map, out, indent, depth),
+    }
+}
+
+fn write_array(items: &[JsonValue], out: &mut String, indent: Option<usize>, depth: usize) {
+    if items.is_empty() {
+        out.push_str("[]");
+        return;
+    }
+
+    out.push('[');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        newline_indent(out, indent, depth + 1);
+        write_value(item, out, indent, depth + 1);
+    }
+    newline_indent(out, indent, depth);
+    out.push(']');
+}
+
+fn write_object(map: &std::collections::HashMap<String, JsonValue>, out: &mut String, indent: Option<usize>, depth: usize) {
+    if map.is_empty() {
+        out.push_str("{}");
+        return;
+    }
+
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort();
+
+    out.push('{');
+    for (i, key) in keys.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        newline_indent(out, indent, depth + 1);
+        write_string(key, out);
+        out.push(':');
+        if indent.is_some() {
+            out.push(' ');
+        }
+        write_value(&map[*key], out, indent, depth + 1);
+    }
+    newline_indent(out, indent, depth);
+    out.push('}');
+}
+
+fn newline_indent(out: &mut String, indent: Option<usize>, depth: usize) {
+    if let Some(width) = indent {
+        out.push('\n');
+        out.push_str(&" ".repeat(width * depth));
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_string, to_string_pretty};
+    use crate::{JsonValue, Number};
+    use std::collections::HashMap;
+
+    fn sample() -> JsonValue {
+        let mut obj = HashMap::new();
+        obj.insert("b".to_string(), JsonValue::Number(Number::PosInt(1)));
+        obj.insert("a".to_string(), JsonValue::String("hi\n\"there\"".to_string()));
+        JsonValue::Object(obj)
+    }
+
+    #[test]
+    fn compact_escapes_and_sorts_keys() {
+        assert_eq!(to_string(&sample()), r#"{"a":"hi\n\"there\"","b":1}"#);
+    }
+
+    #[test]
+    fn pretty_indents_nested_values() {
+        let pretty = to_string_pretty(&sample(), 2);
+        assert_eq!(pretty, "{\n  \"a\": \"hi\\n\\\"there\\\"\",\n  \"b\": 1\n}");
+    }
+
+    #[test]
+    fn numbers_have_no_trailing_zero() {
+        let value = JsonValue::Array(vec![JsonValue::Number(Number::Float(5.0)), JsonValue::Number(Number::Float(5.5))]);
+        assert_eq!(to_string(&value), "[5,5.5]");
+    }
+
+    #[test]
+    fn empty_containers_round_trip() {
+        assert_eq!(to_string(&JsonValue::Array(vec![])), "[]");
+        assert_eq!(to_string(&JsonValue::Object(HashMap::new())), "{}");
+    }
+}
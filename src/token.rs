@@ -0,0 +1,41 @@
+/// A byte range into the source text a [`Token`] was scanned from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn as_str(self, input: &str) -> &str {
+        &input[self.start..self.end]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Colon,
+    Comma,
+    String,
+    Number,
+    True,
+    False,
+    Null,
+    /// A byte that doesn't start any valid JSON token; always `malformed`.
+    Unknown,
+}
+
+/// A single lexical token over the source `&str`. Carries only its kind and
+/// span, not a decoded value: strings keep their surrounding quotes and
+/// escapes, numbers keep their raw lexeme, so the tokenizer stays
+/// allocation-free. `malformed` is set instead of failing outright, so a
+/// caller can decide how to report (or recover from) the error.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+    pub malformed: bool,
+}
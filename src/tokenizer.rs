@@ -0,0 +1,206 @@
+use crate::token::{Span, Token, TokenKind};
+
+/// An allocation-free tokenizer over a `&str`, separate from value
+/// construction and error reporting (mirrors rustc_lexer's split between
+/// pure lexing and diagnostics). It never allocates and never fails: an
+/// input that doesn't form a valid token still yields a `Token` with
+/// `malformed` set, so the caller decides how to report or recover.
+pub(crate) struct Tokenizer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Tokenizer { input, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.rest().chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek_char(), Some(c) if c.is_ascii_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn scan_string(&mut self) -> Token {
+        let start = self.pos;
+        self.bump(); // opening quote
+        let mut malformed = false;
+
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => {
+                    if self.bump().is_none() {
+                        malformed = true;
+                        break;
+                    }
+                },
+                Some(_) => {},
+                None => {
+                    malformed = true;
+                    break;
+                },
+            }
+        }
+
+        Token { kind: TokenKind::String, span: Span { start, end: self.pos }, malformed }
+    }
+
+    fn scan_number(&mut self) -> Token {
+        let start = self.pos;
+        let mut saw_digit = false;
+
+        if self.peek_char() == Some('-') {
+            self.bump();
+        }
+        while matches!(self.peek_char(), Some(c) if c.is_ascii_digit()) {
+            self.bump();
+            saw_digit = true;
+        }
+        if self.peek_char() == Some('.') {
+            self.bump();
+            while matches!(self.peek_char(), Some(c) if c.is_ascii_digit()) {
+                self.bump();
+                saw_digit = true;
+            }
+        }
+        if matches!(self.peek_char(), Some('e') | Some('E')) {
+            self.bump();
+            if matches!(self.peek_char(), Some('+') | Some('-')) {
+                self.bump();
+            }
+            while matches!(self.peek_char(), Some(c) if c.is_ascii_digit()) {
+                self.bump();
+            }
+        }
+
+        Token { kind: TokenKind::Number, span: Span { start, end: self.pos }, malformed: !saw_digit }
+    }
+
+    fn scan_keyword(&mut self, keyword: &str, kind: TokenKind) -> Token {
+        let start = self.pos;
+        let matched = self.rest().starts_with(keyword);
+        if matched {
+            self.pos += keyword.len();
+        } else {
+            self.bump();
+        }
+        Token { kind, span: Span { start, end: self.pos }, malformed: !matched }
+    }
+
+    fn simple(&mut self, kind: TokenKind) -> Token {
+        let start = self.pos;
+        self.bump();
+        Token { kind, span: Span { start, end: self.pos }, malformed: false }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        self.skip_whitespace();
+        let c = self.peek_char()?;
+
+        Some(match c {
+            '{' => self.simple(TokenKind::LBrace),
+            '}' => self.simple(TokenKind::RBrace),
+            '[' => self.simple(TokenKind::LBracket),
+            ']' => self.simple(TokenKind::RBracket),
+            ':' => self.simple(TokenKind::Colon),
+            ',' => self.simple(TokenKind::Comma),
+            '"' => self.scan_string(),
+            c if c == '-' || c.is_ascii_digit() => self.scan_number(),
+            't' => self.scan_keyword("true", TokenKind::True),
+            'f' => self.scan_keyword("false", TokenKind::False),
+            'n' => self.scan_keyword("null", TokenKind::Null),
+            _ => {
+                let start = self.pos;
+                self.bump();
+                Token { kind: TokenKind::Unknown, span: Span { start, end: self.pos }, malformed: true }
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &str) -> Vec<(TokenKind, bool)> {
+        Tokenizer::new(input).map(|tok| (tok.kind, tok.malformed)).collect()
+    }
+
+    #[test]
+    fn well_formed_tokens_are_not_malformed() {
+        assert_eq!(
+            kinds(r#"{"a": [1, true, false, null]}"#),
+            vec![
+                (TokenKind::LBrace, false),
+                (TokenKind::String, false),
+                (TokenKind::Colon, false),
+                (TokenKind::LBracket, false),
+                (TokenKind::Number, false),
+                (TokenKind::Comma, false),
+                (TokenKind::True, false),
+                (TokenKind::Comma, false),
+                (TokenKind::False, false),
+                (TokenKind::Comma, false),
+                (TokenKind::Null, false),
+                (TokenKind::RBracket, false),
+                (TokenKind::RBrace, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_string_is_flagged_malformed() {
+        assert_eq!(kinds(r#""abc"#), vec![(TokenKind::String, true)]);
+    }
+
+    #[test]
+    fn bare_sign_with_no_digits_is_flagged_malformed() {
+        assert_eq!(kinds("-"), vec![(TokenKind::Number, true)]);
+    }
+
+    #[test]
+    fn misspelled_keyword_is_flagged_malformed() {
+        // "truefoo" splits into a well-formed `true` token followed by
+        // malformed tokens for the unrecognized remainder, byte by byte.
+        assert_eq!(
+            kinds("truefoo"),
+            vec![
+                (TokenKind::True, false),
+                (TokenKind::False, true),
+                (TokenKind::Unknown, true),
+                (TokenKind::Unknown, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn unrecognized_byte_is_flagged_malformed() {
+        assert_eq!(kinds("~"), vec![(TokenKind::Unknown, true)]);
+    }
+
+    #[test]
+    fn spans_cover_the_exact_lexeme() {
+        let input = r#"  "hi"  "#;
+        let tok = Tokenizer::new(input).next().unwrap();
+        assert_eq!(tok.span.as_str(input), "\"hi\"");
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::JsonValue;
+
+impl JsonValue {
+    /// Looks up `key` on an object value; `None` for any other variant.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    /// Looks up index `i` on an array value; `None` for any other variant.
+    pub fn index(&self, i: usize) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Array(arr) => arr.get(i),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(arr) => Some(arr),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&HashMap<String, JsonValue>> {
+        match self {
+            JsonValue::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(n) => n.as_f64(),
+            _ => None,
+        }
+    }
+
+    /// The exact integer value, if this is a number that fits in an `i64`
+    /// without going through a lossy `f64` conversion.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            JsonValue::Number(n) => n.as_i64(),
+            _ => None,
+        }
+    }
+
+    /// The exact integer value, if this is a number that fits in a `u64`
+    /// without going through a lossy `f64` conversion.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JsonValue::Number(n) => n.as_u64(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Number;
+
+    fn object() -> JsonValue {
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), JsonValue::Number(Number::PosInt(1)));
+        JsonValue::Object(map)
+    }
+
+    fn array() -> JsonValue {
+        JsonValue::Array(vec![JsonValue::Boolean(true), JsonValue::Null])
+    }
+
+    #[test]
+    fn get_looks_up_a_key_on_an_object() {
+        assert_eq!(object().get("a").and_then(JsonValue::as_u64), Some(1));
+        assert!(object().get("missing").is_none());
+    }
+
+    #[test]
+    fn get_is_none_on_non_object_variants() {
+        assert!(array().get("a").is_none());
+        assert!(JsonValue::Null.get("a").is_none());
+    }
+
+    #[test]
+    fn index_looks_up_a_position_on_an_array() {
+        assert_eq!(array().index(0).and_then(JsonValue::as_bool), Some(true));
+        assert!(array().index(5).is_none());
+    }
+
+    #[test]
+    fn index_is_none_on_non_array_variants() {
+        assert!(object().index(0).is_none());
+        assert!(JsonValue::Boolean(true).index(0).is_none());
+    }
+}